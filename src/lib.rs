@@ -43,23 +43,71 @@ pub struct YourEnumArray<T> {
 * Count the number of variants with `YourEnum::count()` or `YourEnum::len()`.
 
 * Make an array which can only be indexed by your enum.
-The `enhanced_enum!` macro generates a wrapper around a standard array,
-and this custom array type implements a very similar API to a standard array.
-The name of the new array type is the enum name with the word "Array" appended.
+  The `enhanced_enum!` macro generates a wrapper around a standard array,
+  and this custom array type implements a very similar API to a standard array.
+  The name of the new array type is the enum name with the word "Array" appended.
+    + Construct one with `new`, `new_with`, `from_fn`, or the fallible
+      `try_new_with`, or collect one from an iterator of `(YourEnum, T)` pairs.
+    + Combine two arrays with `zip`/`zip_map`, or collapse one with
+      `fold`/`reduce`.
+    + `AsRef<[T]>`, `AsMut<[T]>`, `Borrow<[T]>`, `BorrowMut<[T]>`, and
+      `Display` (when `T: Display`, printing `"Variant: value"` pairs).
+
+* Make a compact set of your enum's variants, backed by a single unsigned
+  integer (sized to the number of variants, up to 128 of them).
+  The name of the new set type is the enum name with the word "Set" appended.
+  This is `Copy` and supports the usual set operations (`|`, `&`, `^`, `!`,
+  `is_subset`, etc) as cheap bitwise operations.
 
 * Convert between integers, strings, and enhanced enums.
     + `YourEnum::try_from(usize)`
-    Also works with `u32` and `64`.
-    + `YourEnum::try_from(&str)`
-    Note that the string must exactly match a variant name, or else this returns an error.
+      Also works with `u32` and `u64`.
+    + `YourEnum::try_from(&str)`, and `"1".parse::<YourEnum>()` via `FromStr`.
+      A string is accepted if it matches a variant name, or if it parses as
+      the decimal discriminant of a variant.
     + `your_enum as usize`.
     + `your_enum.to_string() -> String`
     + `your_enum.to_str() -> &'static str`
 
+* Assign explicit discriminant values, just like a plain Rust enum:
+  `enhanced_enum!(Errno { EPerm = 1, ENoEnt = 2, EIo = 5 })`. Omitted
+  discriminants default to one more than the previous variant. Gaps in the
+  discriminants never waste space: `YourEnumArray` is always backed by a
+  dense array, and `YourEnumSet` by one bit per variant, both indexed by
+  declaration order, not by discriminant.
+
+* Attach static string properties to individual variants:
+  `enhanced_enum!(Planet { Earth { mass: "5.97e24", moons: "1" }, Mars { mass: "6.4e23", moons: "2" } })`.
+    + `your_enum.get("mass") -> Option<&'static str>` looks up a property by key.
+    + `your_enum.message()`/`your_enum.detailed_message()` look up the
+      conventional `message`/`detailed_message` keys, with `detailed_message`
+      falling back to `message` when only the latter is set.
+
+* Customize string conversion with two opt-in macro arguments:
+    + `case_insensitive`, which makes `try_from(&str)`/`FromStr` ignore case.
+    + `rename_all = "..."`, which controls the spelling used by `to_str`,
+      `Display`, and parsing. Supported styles: `"snake_case"`,
+      `"kebab-case"`, `"SCREAMING_SNAKE_CASE"`, `"camelCase"`, and
+      `"PascalCase"`. Each variant name is split on its existing case
+      boundaries and re-joined in the requested style.
+
 * Interface with Python via the `pyo3` library.
-Currently this only implements a converting from python strings to rust.
-This is optionally compiled.
-To opt-in: build the enhanced_enum crate using the feature flag "pyo3".
+  `YourEnum` is registered as a `#[pyclass]`, with each variant exposed as a
+  class attribute, so Rust and Python can pass these enums back and forth as
+  first-class objects (`into_py`/`to_object`, and extraction back out again
+  via pyo3's own `FromPyObject` impl for pyclasses).
+  From the Python side it behaves like an `IntEnum`: `int(your_enum)`,
+  `str(your_enum)`, `repr(your_enum)`, hashing, and the comparison operators
+  all work, comparing by each variant's underlying integer value.
+  This is optionally compiled.
+  To opt-in: build the enhanced_enum crate using the feature flag "pyo3".
+
+* Serialize and deserialize with `serde`.
+  The enum serializes as its variant name string, and the array serializes
+  as a map keyed by variant name (so that it round-trips regardless of
+  declaration order, and deserializing requires every variant to be present).
+  This is optionally compiled.
+  To opt-in: build the enhanced_enum crate using the feature flag "serde".
 
 ### Examples
 
@@ -90,10 +138,6 @@ assert_eq!(nucleotide_count[Nucleotide::T], 12);
 
 // TODO: Allow the user to put doc-strings on their enums.
 
-// TODO: Arrays should implement the following traits:
-//          Display if T is also Display.
-//          AsRef, AsMut, Borrow, BorrowMut.
-
 // TODO: Analyze the assembly output and verify that the array access methods
 // are not bounds checked. If they are then manually do `get_unchecked` BC the
 // enums are always valid indexes into the array.
@@ -106,11 +150,130 @@ assert_eq!(nucleotide_count[Nucleotide::T], 12);
 /// enhanced enums and associated arrays.
 #[macro_export]
 macro_rules! enhanced_enum {
-    ($name:ident $(,)? {$($variants:ident$(,)?)*}) => {
+    ($name:ident $(,)? {$($variants:ident $(= $discriminants:literal)? $({$($keys:ident : $values:literal),* $(,)?})? $(,)?)*}) => {
+        enhanced_enum::enhanced_enum!(@impl $name, Verbatim, false, {$($variants $(= $discriminants)? $({$($keys : $values),*})?,)*});
+    };
+    ($name:ident, case_insensitive $(,)? {$($variants:ident $(= $discriminants:literal)? $({$($keys:ident : $values:literal),* $(,)?})? $(,)?)*}) => {
+        enhanced_enum::enhanced_enum!(@impl $name, Verbatim, true, {$($variants $(= $discriminants)? $({$($keys : $values),*})?,)*});
+    };
+    ($name:ident, rename_all = "snake_case" $(,)? {$($variants:ident $(= $discriminants:literal)? $({$($keys:ident : $values:literal),* $(,)?})? $(,)?)*}) => {
+        enhanced_enum::enhanced_enum!(@impl $name, SnakeCase, false, {$($variants $(= $discriminants)? $({$($keys : $values),*})?,)*});
+    };
+    ($name:ident, rename_all = "snake_case", case_insensitive $(,)? {$($variants:ident $(= $discriminants:literal)? $({$($keys:ident : $values:literal),* $(,)?})? $(,)?)*}) => {
+        enhanced_enum::enhanced_enum!(@impl $name, SnakeCase, true, {$($variants $(= $discriminants)? $({$($keys : $values),*})?,)*});
+    };
+    ($name:ident, rename_all = "kebab-case" $(,)? {$($variants:ident $(= $discriminants:literal)? $({$($keys:ident : $values:literal),* $(,)?})? $(,)?)*}) => {
+        enhanced_enum::enhanced_enum!(@impl $name, KebabCase, false, {$($variants $(= $discriminants)? $({$($keys : $values),*})?,)*});
+    };
+    ($name:ident, rename_all = "kebab-case", case_insensitive $(,)? {$($variants:ident $(= $discriminants:literal)? $({$($keys:ident : $values:literal),* $(,)?})? $(,)?)*}) => {
+        enhanced_enum::enhanced_enum!(@impl $name, KebabCase, true, {$($variants $(= $discriminants)? $({$($keys : $values),*})?,)*});
+    };
+    ($name:ident, rename_all = "SCREAMING_SNAKE_CASE" $(,)? {$($variants:ident $(= $discriminants:literal)? $({$($keys:ident : $values:literal),* $(,)?})? $(,)?)*}) => {
+        enhanced_enum::enhanced_enum!(@impl $name, ScreamingSnakeCase, false, {$($variants $(= $discriminants)? $({$($keys : $values),*})?,)*});
+    };
+    ($name:ident, rename_all = "SCREAMING_SNAKE_CASE", case_insensitive $(,)? {$($variants:ident $(= $discriminants:literal)? $({$($keys:ident : $values:literal),* $(,)?})? $(,)?)*}) => {
+        enhanced_enum::enhanced_enum!(@impl $name, ScreamingSnakeCase, true, {$($variants $(= $discriminants)? $({$($keys : $values),*})?,)*});
+    };
+    ($name:ident, rename_all = "camelCase" $(,)? {$($variants:ident $(= $discriminants:literal)? $({$($keys:ident : $values:literal),* $(,)?})? $(,)?)*}) => {
+        enhanced_enum::enhanced_enum!(@impl $name, CamelCase, false, {$($variants $(= $discriminants)? $({$($keys : $values),*})?,)*});
+    };
+    ($name:ident, rename_all = "camelCase", case_insensitive $(,)? {$($variants:ident $(= $discriminants:literal)? $({$($keys:ident : $values:literal),* $(,)?})? $(,)?)*}) => {
+        enhanced_enum::enhanced_enum!(@impl $name, CamelCase, true, {$($variants $(= $discriminants)? $({$($keys : $values),*})?,)*});
+    };
+    ($name:ident, rename_all = "PascalCase" $(,)? {$($variants:ident $(= $discriminants:literal)? $({$($keys:ident : $values:literal),* $(,)?})? $(,)?)*}) => {
+        enhanced_enum::enhanced_enum!(@impl $name, PascalCase, false, {$($variants $(= $discriminants)? $({$($keys : $values),*})?,)*});
+    };
+    ($name:ident, rename_all = "PascalCase", case_insensitive $(,)? {$($variants:ident $(= $discriminants:literal)? $({$($keys:ident : $values:literal),* $(,)?})? $(,)?)*}) => {
+        enhanced_enum::enhanced_enum!(@impl $name, PascalCase, true, {$($variants $(= $discriminants)? $({$($keys : $values),*})?,)*});
+    };
+    ($name:ident, rename_all = $style:literal $(, case_insensitive)? $(,)? {$($variants:ident $(= $discriminants:literal)? $({$($keys:ident : $values:literal),* $(,)?})? $(,)?)*}) => {
+        compile_error!(concat!(
+            "enhanced_enum: unsupported rename_all style ", stringify!($style),
+            ", expected one of \"snake_case\", \"kebab-case\", \"SCREAMING_SNAKE_CASE\", \"camelCase\", \"PascalCase\""
+        ));
+    };
+
+    // Default (and `case_insensitive`-only) naming: `to_str` returns the
+    // variant's own identifier verbatim, with no renaming lookup table.
+    (@impl $name:ident, Verbatim, $insensitive:literal, {$($variants:ident $(= $discriminants:literal)? $({$($keys:ident : $values:literal),* $(,)?})?,)*}) => {
+        enhanced_enum::enhanced_enum!(@body $name, {$($variants $(= $discriminants)? $({$($keys : $values),*})?,)*});
+
+        impl $name {
+            pub fn to_str(&self) -> &'static str {
+                match self {
+                    $( $name::$variants => stringify!($variants), )*
+                }
+            }
+        }
+
+        enhanced_enum::enhanced_enum!(@parsing $name, $insensitive);
+    };
+
+    // `rename_all`: `to_str` looks up a lazily-computed, case-converted name.
+    (@impl $name:ident, $style:ident, $insensitive:literal, {$($variants:ident $(= $discriminants:literal)? $({$($keys:ident : $values:literal),* $(,)?})?,)*}) => {
+        enhanced_enum::enhanced_enum!(@body $name, {$($variants $(= $discriminants)? $({$($keys : $values),*})?,)*});
 
+        impl $name {
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    $( $name::$variants => stringify!($variants), )*
+                }
+            }
+        }
+
+        enhanced_enum::paste::paste! {
+            static [<$name _RENAMED>]: std::sync::OnceLock<std::vec::Vec<&'static str>> = std::sync::OnceLock::new();
+
+            impl $name {
+                pub fn to_str(&self) -> &'static str {
+                    [<$name _RENAMED>].get_or_init(|| {
+                        $name::iter().map(|v| {
+                            let renamed = enhanced_enum::rename(v.variant_name(), enhanced_enum::RenameStyle::$style);
+                            std::boxed::Box::leak(renamed.into_boxed_str()) as &'static str
+                        }).collect()
+                    })[self.ordinal()]
+                }
+            }
+        }
+
+        enhanced_enum::enhanced_enum!(@parsing $name, $insensitive);
+    };
+
+    // Shared string-parsing impls: accept a decimal discriminant, or a name
+    // matching `to_str` (honoring `$insensitive`).
+    (@parsing $name:ident, $insensitive:literal) => {
+        impl std::convert::TryFrom<&str> for $name {
+            type Error = &'static str;
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                use std::convert::TryFrom;
+                if let Ok(discriminant) = value.parse::<usize>() {
+                    if let Ok(variant) = $name::try_from(discriminant) {
+                        return Ok(variant);
+                    }
+                }
+                for variant in $name::iter() {
+                    if enhanced_enum::name_matches(value, variant.to_str(), $insensitive) {
+                        return Ok(variant);
+                    }
+                }
+                Err("Unrecognized variant name!")
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = &'static str;
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                use std::convert::TryFrom;
+                $name::try_from(value)
+            }
+        }
+    };
+
+    (@body $name:ident, {$($variants:ident $(= $discriminants:literal)? $({$($keys:ident : $values:literal),* $(,)?})?,)*}) => {
         #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "pyo3", pyo3::pyclass)]
         pub enum $name {
-            $( $variants ),*
+            $( $variants $(= $discriminants)? ),*
         }
 
         impl $name {
@@ -122,24 +285,39 @@ macro_rules! enhanced_enum {
 
             pub const fn is_empty() -> bool { $name::len() == 0 }
 
-            /// Iterate over all variants in this enum, in sorted order.
+            /// Iterate over all variants in this enum, in declaration order.
             pub fn iter() -> impl std::iter::Iterator<Item=$name> {
-                (0..Self::count()).map(|x| match x {
-                    $( _ if x == $name::$variants as usize => $name::$variants, )*
-                    _ => panic!()
-                })
+                std::iter::IntoIterator::into_iter([$( $name::$variants ),*])
             }
+        }
 
-            pub fn to_str(&self) -> &'static str {
+        impl $name {
+            /// Look up a static property attached to this variant with
+            /// `Variant { key: "value", ... }` syntax, if one was set.
+            pub fn get(&self, key: &str) -> Option<&'static str> {
                 match self {
-                    $( $name::$variants => stringify!($variants), )*
+                    $( $name::$variants => match key {
+                        $($( stringify!($keys) => Some($values), )*)?
+                        _ => None,
+                    }, )*
                 }
             }
+
+            /// This variant's `message` property, if one was set.
+            pub fn message(&self) -> Option<&'static str> {
+                self.get("message")
+            }
+
+            /// This variant's `detailed_message` property, falling back to
+            /// its `message` property if `detailed_message` wasn't set.
+            pub fn detailed_message(&self) -> Option<&'static str> {
+                self.get("detailed_message").or_else(|| self.message())
+            }
         }
 
         impl std::fmt::Display for $name {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f, "{:?}", self)
+                write!(f, "{}", self.to_str())
             }
         }
 
@@ -159,7 +337,9 @@ macro_rules! enhanced_enum {
         impl std::convert::TryFrom<u64> for $name {
             type Error = &'static str;
             fn try_from(value: u64) -> Result<Self, Self::Error> {
-                let value = u32::try_from(value).unwrap();
+                let Ok(value) = u32::try_from(value) else {
+                    return Err("Bad enum discriminant!");
+                };
                 $name::try_from(value)
             }
         }
@@ -167,23 +347,43 @@ macro_rules! enhanced_enum {
         impl std::convert::TryFrom<usize> for $name {
             type Error = &'static str;
             fn try_from(value: usize) -> Result<Self, Self::Error> {
-                let value = u32::try_from(value).unwrap();
+                let Ok(value) = u32::try_from(value) else {
+                    return Err("Bad enum discriminant!");
+                };
                 $name::try_from(value)
             }
         }
 
-        impl std::convert::TryFrom<&str> for $name {
-            type Error = &'static str;
-            fn try_from(value: &str) -> Result<Self, Self::Error> {
-                match value {
-                    $( _ if value == $name::$variants.to_string() => Ok($name::$variants), )*
-                    _ => Err("Unrecognized variant name!")
+        enhanced_enum::paste::paste! {
+            // A hidden companion enum sharing the same variant idents but
+            // with no explicit discriminants, so Rust auto-assigns it a
+            // dense 0..count() numbering. This lets `ordinal()` recover a
+            // variant's position in declaration order in O(1), independent
+            // of whatever (possibly sparse) discriminant it was given above.
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            enum [<$name Ordinal>] {
+                $( $variants ),*
+            }
+
+            impl $name {
+                /// This variant's position in declaration order, `0..count()`.
+                ///
+                /// Used to index the dense backing arrays of `YourEnumArray`
+                /// and `YourEnumSet`, which stay compact even when explicit
+                /// discriminants are sparse.
+                fn ordinal(&self) -> usize {
+                    match self {
+                        $( $name::$variants => [<$name Ordinal>]::$variants as usize, )*
+                    }
                 }
             }
         }
 
         enhanced_enum::pyo3_traits!($name, {$($variants,)*});
 
+        enhanced_enum::serde_traits!($name, {$($variants,)*});
+
         enhanced_enum::paste::paste! {
             /// Container to associate each enum variant with a datum.
             ///
@@ -206,15 +406,56 @@ macro_rules! enhanced_enum {
                 pub fn new_with<F>(initial_value: F) -> Self
                     where F: Fn($name) -> T
                 {
-                    use std::convert::TryFrom;
-                    use std::mem::{MaybeUninit, forget, replace};
-                    let mut data: [T; $name::count()] = unsafe {
-                        MaybeUninit::uninit().assume_init()
+                    match Self::try_new_with(|v| Ok::<T, std::convert::Infallible>(initial_value(v))) {
+                        Ok(array) => array,
+                        Err(infallible) => match infallible {},
+                    }
+                }
+
+                /// Like [`Self::new_with`], but the closure may fail. If it
+                /// fails for any variant, construction stops and that error
+                /// is returned.
+                ///
+                /// Builds the array through `[MaybeUninit<T>; N]`, writing
+                /// one slot at a time behind a drop-guard that tracks how
+                /// many slots are initialized. If `initial_value` panics or
+                /// returns `Err` partway through, the guard drops exactly
+                /// the already-initialized prefix (and nothing else) on its
+                /// way out, so there's no leak and no read of uninitialized
+                /// memory.
+                pub fn try_new_with<F, E>(initial_value: F) -> Result<Self, E>
+                    where F: Fn($name) -> Result<T, E>
+                {
+                    struct Guard<T> {
+                        data: [std::mem::MaybeUninit<T>; $name::count()],
+                        initialized: usize,
+                    }
+
+                    impl<T> Drop for Guard<T> {
+                        fn drop(&mut self) {
+                            for elem in &mut self.data[..self.initialized] {
+                                unsafe { elem.assume_init_drop(); }
+                            }
+                        }
+                    }
+
+                    let variants = [$($name::$variants),*];
+                    let mut guard = Guard {
+                        data: unsafe { std::mem::MaybeUninit::uninit().assume_init() },
+                        initialized: 0,
                     };
-                    for (idx, elem) in data.iter_mut().enumerate() {
-                        forget(replace(elem, initial_value($name::try_from(idx).unwrap())));
+                    for (idx, variant) in std::iter::IntoIterator::into_iter(variants).enumerate() {
+                        guard.data[idx] = std::mem::MaybeUninit::new(initial_value(variant)?);
+                        guard.initialized = idx + 1;
                     }
-                    return Self { data };
+
+                    let data = unsafe {
+                        let data_ptr = guard.data.as_ptr() as *const [T; $name::count()];
+                        let data = data_ptr.read();
+                        std::mem::forget(guard);
+                        data
+                    };
+                    Ok(Self { data })
                 }
 
                 pub const fn len(&self) -> usize { $name::count() }
@@ -231,14 +472,14 @@ macro_rules! enhanced_enum {
 
                 /// Iterate and Enumerate, where Enumerate yields enum variants instead of usize.
                 pub fn iter_enumerate<'a>(&'a self) -> impl std::iter::Iterator<Item=($name, &T)> {
-                    use std::convert::TryFrom;
-                    self.data.iter().enumerate().map(|(idx, v)| ($name::try_from(idx).unwrap(),v))
+                    let variants = [$($name::$variants),*];
+                    self.data.iter().enumerate().map(move |(idx, v)| (variants[idx], v))
                 }
 
                 /// Iterate and Enumerate, where Enumerate yields enum variants instead of usize.
                 pub fn iter_mut_enumerate<'a>(&'a mut self) -> impl std::iter::Iterator<Item=($name, &mut T)> {
-                    use std::convert::TryFrom;
-                    self.data.iter_mut().enumerate().map(|(idx, v)| ($name::try_from(idx).unwrap(),v))
+                    let variants = [$($name::$variants),*];
+                    self.data.iter_mut().enumerate().map(move |(idx, v)| (variants[idx], v))
                 }
 
                 /// Returns an array like self, with function f applied to each element.
@@ -250,6 +491,89 @@ macro_rules! enhanced_enum {
                 pub fn contains(&self, x: &T) -> bool where T: PartialEq<T> {
                     self.data.contains(x)
                 }
+
+                /// Equivalent to [`Self::new_with`], named to match the
+                /// convention of [`std::array::from_fn`].
+                pub fn from_fn<F>(initial_value: F) -> Self where F: Fn($name) -> T {
+                    Self::new_with(initial_value)
+                }
+
+                /// Combines this array with another into an array of pairs,
+                /// one per variant.
+                pub fn zip<U>(self, other: [<$name Array>]<U>) -> [<$name Array>]<(T, U)> {
+                    self.zip_map(other, |t, u| (t, u))
+                }
+
+                /// Combines this array with another, applying `f` to each
+                /// pair of elements, one per variant.
+                pub fn zip_map<U, Q, F>(self, other: [<$name Array>]<U>, f: F) -> [<$name Array>]<Q>
+                    where F: Fn(T, U) -> Q
+                {
+                    let lhs = std::cell::RefCell::new(std::iter::IntoIterator::into_iter(self.data));
+                    let rhs = std::cell::RefCell::new(std::iter::IntoIterator::into_iter(other.data));
+                    [<$name Array>]::new_with(|_| {
+                        f(lhs.borrow_mut().next().unwrap(), rhs.borrow_mut().next().unwrap())
+                    })
+                }
+
+                /// Folds over the array's elements, in declaration order.
+                pub fn fold<B, F>(&self, init: B, f: F) -> B where F: Fn(B, &T) -> B {
+                    self.data.iter().fold(init, f)
+                }
+
+                /// Reduces the array's elements into one, in declaration
+                /// order. Returns `None` only if the enum has no variants.
+                pub fn reduce<F>(self, f: F) -> Option<T> where F: Fn(T, T) -> T {
+                    let mut iter = std::iter::IntoIterator::into_iter(self.data);
+                    iter.next().map(|first| iter.fold(first, f))
+                }
+            }
+
+            impl<T> std::convert::AsRef<[T]> for [<$name Array>]<T> {
+                fn as_ref(&self) -> &[T] { &self.data }
+            }
+
+            impl<T> std::convert::AsMut<[T]> for [<$name Array>]<T> {
+                fn as_mut(&mut self) -> &mut [T] { &mut self.data }
+            }
+
+            impl<T> std::borrow::Borrow<[T]> for [<$name Array>]<T> {
+                fn borrow(&self) -> &[T] { &self.data }
+            }
+
+            impl<T> std::borrow::BorrowMut<[T]> for [<$name Array>]<T> {
+                fn borrow_mut(&mut self) -> &mut [T] { &mut self.data }
+            }
+
+            impl<T> std::fmt::Display for [<$name Array>]<T> where T: std::fmt::Display {
+                /// Prints `"Variant: value"` pairs, one per variant, separated by `", "`.
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    for (idx, (variant, value)) in self.iter_enumerate().enumerate() {
+                        if idx > 0 {
+                            write!(f, ", ")?;
+                        }
+                        write!(f, "{}: {}", variant.to_str(), value)?;
+                    }
+                    Ok(())
+                }
+            }
+
+            impl<T> std::iter::FromIterator<($name, T)> for [<$name Array>]<T> {
+                /// Builds the array from an iterator of `(variant, value)`
+                /// pairs. Panics if any variant is missing from the iterator.
+                fn from_iter<I: std::iter::IntoIterator<Item = ($name, T)>>(iter: I) -> Self {
+                    let mut slots: [<$name Array>]<Option<T>> = [<$name Array>]::new_with(|_| None);
+                    for (variant, value) in iter {
+                        slots[variant] = Some(value);
+                    }
+                    for variant in $name::iter() {
+                        if slots[variant].is_none() {
+                            panic!("missing entry for {} when collecting into {}", variant.to_str(), stringify!($name));
+                        }
+                    }
+                    let slots = std::cell::RefCell::new(slots);
+                    [<$name Array>]::new_with(|v| slots.borrow_mut()[v].take().unwrap())
+                }
             }
 
             impl<T> std::fmt::Debug for [<$name Array>]<T> where T: std::fmt::Debug {
@@ -263,13 +587,13 @@ macro_rules! enhanced_enum {
             impl<T> std::ops::Index<$name> for [<$name Array>]<T> {
                 type Output = T;
                 fn index(&self, x: $name) -> &Self::Output {
-                    &self.data[x as usize]
+                    &self.data[x.ordinal()]
                 }
             }
 
             impl<T> std::ops::IndexMut<$name> for [<$name Array>]<T> {
                 fn index_mut(&mut self, x: $name) -> &mut Self::Output {
-                    &mut self.data[x as usize]
+                    &mut self.data[x.ordinal()]
                 }
             }
 
@@ -322,8 +646,143 @@ macro_rules! enhanced_enum {
                     self.data.hash(state);
                 }
             }
+
+            /// A compact set of enum variants, stored as a single bitfield.
+            ///
+            /// This is `Copy`, and membership tests and the set operators are
+            /// branch-free bit twiddling, which makes it much cheaper than an
+            /// array of `bool` when all you need is set membership.
+            #[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+            pub struct [<$name Set>] {
+                bits: enhanced_enum::set_repr!($($variants)*),
+            }
+
+            impl [<$name Set>] {
+                /// An empty set.
+                pub const fn new() -> Self { Self { bits: 0 } }
+
+                /// An empty set.
+                pub const fn empty() -> Self { Self::new() }
+
+                /// A set containing every variant.
+                pub fn all() -> Self {
+                    let num_bits = $name::count() as u32;
+                    let repr_bits = (std::mem::size_of::<enhanced_enum::set_repr!($($variants)*)>() * 8) as u32;
+                    let bits = if num_bits == 0 {
+                        0
+                    } else if num_bits >= repr_bits {
+                        !0
+                    } else {
+                        (1 << num_bits) - 1
+                    };
+                    Self { bits }
+                }
+
+                pub fn insert(&mut self, value: $name) {
+                    self.bits |= 1 << (value.ordinal() as u32);
+                }
+
+                pub fn remove(&mut self, value: $name) {
+                    self.bits &= !(1 << (value.ordinal() as u32));
+                }
+
+                pub fn contains(&self, value: $name) -> bool {
+                    self.bits & (1 << (value.ordinal() as u32)) != 0
+                }
+
+                pub fn len(&self) -> usize {
+                    self.bits.count_ones() as usize
+                }
+
+                pub fn is_empty(&self) -> bool {
+                    self.bits == 0
+                }
+
+                pub fn is_subset(&self, other: &Self) -> bool {
+                    self.bits & other.bits == self.bits
+                }
+
+                pub fn is_superset(&self, other: &Self) -> bool {
+                    other.is_subset(self)
+                }
+
+                pub fn is_disjoint(&self, other: &Self) -> bool {
+                    self.bits & other.bits == 0
+                }
+
+                /// Iterate over the variants contained in this set, in declaration order.
+                pub fn iter(&self) -> impl std::iter::Iterator<Item = $name> {
+                    let variants = [$($name::$variants),*];
+                    let mut bits = self.bits;
+                    std::iter::from_fn(move || {
+                        if bits == 0 {
+                            None
+                        } else {
+                            let idx = bits.trailing_zeros();
+                            bits &= bits - 1;
+                            Some(variants[idx as usize])
+                        }
+                    })
+                }
+            }
+
+            impl std::fmt::Debug for [<$name Set>] {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.debug_set().entries(self.iter()).finish()
+                }
+            }
+
+            impl std::iter::FromIterator<$name> for [<$name Set>] {
+                fn from_iter<I: std::iter::IntoIterator<Item = $name>>(iter: I) -> Self {
+                    let mut set = Self::new();
+                    for value in iter {
+                        set.insert(value);
+                    }
+                    set
+                }
+            }
+
+            impl std::ops::BitOr for [<$name Set>] {
+                type Output = Self;
+                fn bitor(self, other: Self) -> Self {
+                    Self { bits: self.bits | other.bits }
+                }
+            }
+
+            impl std::ops::BitAnd for [<$name Set>] {
+                type Output = Self;
+                fn bitand(self, other: Self) -> Self {
+                    Self { bits: self.bits & other.bits }
+                }
+            }
+
+            impl std::ops::BitXor for [<$name Set>] {
+                type Output = Self;
+                fn bitxor(self, other: Self) -> Self {
+                    Self { bits: self.bits ^ other.bits }
+                }
+            }
+
+            impl std::ops::Not for [<$name Set>] {
+                type Output = Self;
+                fn not(self) -> Self {
+                    Self { bits: !self.bits } & Self::all()
+                }
+            }
+
+            impl std::ops::BitOrAssign for [<$name Set>] {
+                fn bitor_assign(&mut self, other: Self) { self.bits |= other.bits; }
+            }
+
+            impl std::ops::BitAndAssign for [<$name Set>] {
+                fn bitand_assign(&mut self, other: Self) { self.bits &= other.bits; }
+            }
+
+            impl std::ops::BitXorAssign for [<$name Set>] {
+                fn bitxor_assign(&mut self, other: Self) { self.bits ^= other.bits; }
+            }
         }
-    }
+    };
 }
 
 #[doc(hidden)]
@@ -336,6 +795,128 @@ macro_rules! count {
     ( $x:ident $($xs:ident)* ) => (1_usize + enhanced_enum::count!($($xs)*));
 }
 
+/// The case-conversion styles supported by `enhanced_enum!`'s `rename_all` option.
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub enum RenameStyle {
+    Verbatim,
+    SnakeCase,
+    KebabCase,
+    ScreamingSnakeCase,
+    CamelCase,
+    PascalCase,
+}
+
+/// Splits a Rust identifier on its existing case boundaries and re-joins the
+/// words according to `style`. Used to implement `rename_all`.
+#[doc(hidden)]
+pub fn rename(ident: &str, style: RenameStyle) -> std::string::String {
+    let words = split_words(ident);
+    match style {
+        RenameStyle::Verbatim => ident.to_string(),
+        RenameStyle::SnakeCase => words.iter().map(|w| w.to_lowercase()).collect::<Vec<_>>().join("_"),
+        RenameStyle::KebabCase => words.iter().map(|w| w.to_lowercase()).collect::<Vec<_>>().join("-"),
+        RenameStyle::ScreamingSnakeCase => words.iter().map(|w| w.to_uppercase()).collect::<Vec<_>>().join("_"),
+        RenameStyle::CamelCase => words
+            .iter()
+            .enumerate()
+            .map(|(i, w)| if i == 0 { w.to_lowercase() } else { capitalize(w) })
+            .collect::<Vec<_>>()
+            .join(""),
+        RenameStyle::PascalCase => words.iter().map(|w| capitalize(w)).collect::<Vec<_>>().join(""),
+    }
+}
+
+/// Splits an identifier into words, treating `_` as a separator and each
+/// transition from lowercase to uppercase (or the end of an acronym, e.g.
+/// "HTTPServer" -> ["HTTP", "Server"]) as a word boundary.
+fn split_words(ident: &str) -> std::vec::Vec<std::string::String> {
+    let chars: Vec<char> = ident.chars().collect();
+    let mut words = Vec::new();
+    let mut current = std::string::String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if c.is_uppercase() && !current.is_empty() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_is_lower) {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+fn capitalize(word: &str) -> std::string::String {
+    let mut chars = word.chars();
+    match chars.next() {
+        None => std::string::String::new(),
+        Some(first) => first.to_uppercase().chain(chars.flat_map(|c| c.to_lowercase())).collect(),
+    }
+}
+
+/// Compares a parsed string against a variant's display name, honoring the
+/// `case_insensitive` option.
+#[doc(hidden)]
+pub fn name_matches(input: &str, name: &str, case_insensitive: bool) -> bool {
+    if case_insensitive {
+        input.eq_ignore_ascii_case(name)
+    } else {
+        input == name
+    }
+}
+
+/// Picks the smallest unsigned integer type which has one bit per variant.
+///
+/// This counts variants in groups of 8 (tallying one marker per group, plus
+/// a final partial group) and then maps the number of markers onto the
+/// smallest type that can hold that many bits. It has to happen here, at
+/// macro-expansion time, because `YourEnum::count()` is only known once the
+/// compiler evaluates constants, which is too late to pick a type.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! set_repr {
+    (@accum [$($done:tt)*]) => {
+        enhanced_enum::set_repr!(@pick [$($done)*])
+    };
+    (@accum [$($done:tt)*] $v0:ident $v1:ident $v2:ident $v3:ident $v4:ident $v5:ident $v6:ident $v7:ident $($rest:ident)*) => {
+        enhanced_enum::set_repr!(@accum [$($done)* @] $($rest)*)
+    };
+    (@accum [$($done:tt)*] $($rest:ident)+) => {
+        enhanced_enum::set_repr!(@pick [$($done)* @])
+    };
+    (@pick []) => (u8);
+    (@pick [@]) => (u8);
+    (@pick [@ @]) => (u16);
+    (@pick [@ @ @]) => (u32);
+    (@pick [@ @ @ @]) => (u32);
+    (@pick [@ @ @ @ @]) => (u64);
+    (@pick [@ @ @ @ @ @]) => (u64);
+    (@pick [@ @ @ @ @ @ @]) => (u64);
+    (@pick [@ @ @ @ @ @ @ @]) => (u64);
+    (@pick [@ @ @ @ @ @ @ @ @]) => (u128);
+    (@pick [@ @ @ @ @ @ @ @ @ @]) => (u128);
+    (@pick [@ @ @ @ @ @ @ @ @ @ @]) => (u128);
+    (@pick [@ @ @ @ @ @ @ @ @ @ @ @]) => (u128);
+    (@pick [@ @ @ @ @ @ @ @ @ @ @ @ @]) => (u128);
+    (@pick [@ @ @ @ @ @ @ @ @ @ @ @ @ @]) => (u128);
+    (@pick [@ @ @ @ @ @ @ @ @ @ @ @ @ @ @]) => (u128);
+    (@pick [@ @ @ @ @ @ @ @ @ @ @ @ @ @ @ @]) => (u128);
+    (@pick [$($too_many:tt)*]) => (compile_error!("enhanced_enum: too many variants for a YourEnumSet, the maximum is 128"));
+    ($($variants:ident)*) => {
+        enhanced_enum::set_repr!(@accum [] $($variants)*)
+    };
+}
+
 #[cfg(not(feature = "pyo3"))]
 #[doc(hidden)]
 #[macro_export]
@@ -347,13 +928,153 @@ macro_rules! pyo3_traits {
 #[macro_export]
 macro_rules! pyo3_traits {
     ($name:ident $(,)? {$($variants:ident$(,)?)*}) => {
-        impl pyo3::conversion::FromPyObject<'_> for $name {
-            fn extract(obj: &pyo3::PyAny) -> std::result::Result<Self, pyo3::PyErr> {
-                let string: String = obj.extract()?;
+        // `FromPyObject` is implemented by pyo3 itself, via its blanket impl
+        // for `T: PyClass + Clone`, so Python code can pass instances of this
+        // enum straight back into Rust.
+
+        impl pyo3::ToPyObject for $name {
+            fn to_object(&self, py: pyo3::Python) -> pyo3::PyObject {
+                use pyo3::IntoPy;
+                (*self).into_py(py)
+            }
+        }
+
+        #[pyo3::pymethods]
+        impl $name {
+            fn __int__(&self) -> i64 {
+                // Not `set_repr!`: that's sized off the variant *count*, to
+                // keep `YourEnumSet`'s bitfield small, but discriminants can
+                // be assigned arbitrary explicit values that don't fit in
+                // such a small type. `i64` is wide enough for any real
+                // enum discriminant and avoids silently truncating it.
+                *self as i64
+            }
+
+            fn __str__(&self) -> &'static str {
+                self.to_str()
+            }
+
+            fn __repr__(&self) -> &'static str {
+                self.to_str()
+            }
+
+            fn __hash__(&self) -> u64 {
+                *self as u64
+            }
+
+            fn __richcmp__(
+                &self,
+                py: pyo3::Python,
+                other: &pyo3::PyAny,
+                op: pyo3::basic::CompareOp,
+            ) -> pyo3::PyResult<pyo3::PyObject> {
+                use pyo3::IntoPy;
+                let self_value = *self as usize;
+                let other_value = if let Ok(other) = other.extract::<$name>() {
+                    other as usize
+                } else if let Ok(value) = other.extract::<usize>() {
+                    value
+                } else {
+                    return Ok(py.NotImplemented());
+                };
+                use pyo3::basic::CompareOp::*;
+                let result = match op {
+                    Lt => self_value < other_value,
+                    Le => self_value <= other_value,
+                    Eq => self_value == other_value,
+                    Ne => self_value != other_value,
+                    Gt => self_value > other_value,
+                    Ge => self_value >= other_value,
+                };
+                Ok(result.into_py(py))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! serde_traits {
+    ($name:ident $(,)? {$($variants:ident$(,)?)*}) => {};
+}
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! serde_traits {
+    ($name:ident $(,)? {$($variants:ident$(,)?)*}) => {
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+                where S: serde::Serializer
+            {
+                serializer.serialize_str(self.to_str())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+                where D: serde::Deserializer<'de>
+            {
                 use std::convert::TryFrom;
-                return Ok($name::try_from(string.as_str()).map_err(|err| {
-                    pyo3::PyErr::new::<pyo3::exceptions::PyTypeError, _>(err.to_string())
-                })?);
+                let name = <std::string::String as serde::Deserialize>::deserialize(deserializer)?;
+                $name::try_from(name.as_str()).map_err(serde::de::Error::custom)
+            }
+        }
+
+        enhanced_enum::paste::paste! {
+            impl<T: serde::Serialize> serde::Serialize for [<$name Array>]<T> {
+                /// Serializes as a map keyed by variant name, so that the
+                /// result round-trips regardless of declaration order.
+                fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+                    where S: serde::Serializer
+                {
+                    use serde::ser::SerializeMap;
+                    let mut map = serializer.serialize_map(Some(self.len()))?;
+                    for (variant, value) in self.iter_enumerate() {
+                        map.serialize_entry(variant.to_str(), value)?;
+                    }
+                    map.end()
+                }
+            }
+
+            impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for [<$name Array>]<T> {
+                /// Deserializes from a map keyed by variant name. Every
+                /// variant must be present as a key, or else this fails.
+                fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+                    where D: serde::Deserializer<'de>
+                {
+                    struct ArrayVisitor<T> { marker: std::marker::PhantomData<T> }
+
+                    impl<'de, T: serde::Deserialize<'de>> serde::de::Visitor<'de> for ArrayVisitor<T> {
+                        type Value = [<$name Array>]<T>;
+
+                        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                            write!(f, "a map with one entry per {} variant", stringify!($name))
+                        }
+
+                        fn visit_map<A>(self, mut map: A) -> std::result::Result<Self::Value, A::Error>
+                            where A: serde::de::MapAccess<'de>
+                        {
+                            use std::convert::TryFrom;
+                            let mut slots: [<$name Array>]<Option<T>> = [<$name Array>]::new_with(|_| None);
+                            while let Some(key) = map.next_key::<std::string::String>()? {
+                                let variant = $name::try_from(key.as_str()).map_err(serde::de::Error::custom)?;
+                                slots[variant] = Some(map.next_value()?);
+                            }
+                            for variant in $name::iter() {
+                                if slots[variant].is_none() {
+                                    return Err(serde::de::Error::custom(std::format!(
+                                        "missing key {:?} for {}", variant.to_str(), stringify!($name)
+                                    )));
+                                }
+                            }
+                            let slots = std::cell::RefCell::new(slots);
+                            Ok([<$name Array>]::new_with(|v| slots.borrow_mut()[v].take().unwrap()))
+                        }
+                    }
+
+                    deserializer.deserialize_map(ArrayVisitor { marker: std::marker::PhantomData })
+                }
             }
         }
     };
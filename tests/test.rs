@@ -30,7 +30,274 @@ fn test() {
     for _x in &mut raboof {}
     assert!(!raboof.is_empty());
     assert!(raboof.len() == 2);
-    let boofoo = raboof.clone();
+    let boofoo = raboof;
     assert!(boofoo == raboof);
     let _vv = FooBarArray::new(vec![6; 7]);
 }
+
+#[test]
+fn test_set() {
+    enhanced_enum!(Color { Red, Green, Blue });
+
+    let mut set = ColorSet::new();
+    assert!(set.is_empty());
+    set.insert(Color::Red);
+    set.insert(Color::Blue);
+    assert!(set.contains(Color::Red));
+    assert!(!set.contains(Color::Green));
+    assert_eq!(set.len(), 2);
+
+    let all = ColorSet::all();
+    assert_eq!(all.len(), 3);
+    assert!(all.is_superset(&set));
+    assert!(set.is_subset(&all));
+
+    let complement = !set;
+    assert!(complement.contains(Color::Green));
+    assert!(!complement.contains(Color::Red));
+    assert!(set.is_disjoint(&complement));
+
+    let collected: ColorSet = vec![Color::Red, Color::Green].into_iter().collect();
+    assert_eq!(collected.len(), 2);
+    assert_eq!((set | collected).len(), 3);
+    assert_eq!((set & collected).len(), 1);
+
+    let variants: Vec<Color> = set.iter().collect();
+    assert_eq!(variants, vec![Color::Red, Color::Blue]);
+
+    dbg!(set);
+}
+
+#[test]
+fn test_string_conversion() {
+    use std::str::FromStr;
+
+    enhanced_enum!(Suit { Clubs, Diamonds, Hearts, Spades });
+
+    assert_eq!(Suit::try_from("Hearts").unwrap(), Suit::Hearts);
+    assert_eq!(Suit::try_from("2").unwrap(), Suit::Hearts);
+    assert_eq!(Suit::from_str("Spades").unwrap(), Suit::Spades);
+    assert!(Suit::try_from("hearts").is_err());
+    assert!(Suit::try_from("nonsense").is_err());
+
+    enhanced_enum!(HttpMethod, case_insensitive { Get, Post, Put, Delete });
+    assert_eq!(HttpMethod::try_from("post").unwrap(), HttpMethod::Post);
+    assert_eq!(HttpMethod::try_from("DELETE").unwrap(), HttpMethod::Delete);
+
+    enhanced_enum!(LogLevel, rename_all = "SCREAMING_SNAKE_CASE" { Debug, Info, Warning });
+    assert_eq!(LogLevel::Warning.to_str(), "WARNING");
+    assert_eq!(LogLevel::Warning.to_string(), "WARNING");
+    assert_eq!(LogLevel::try_from("WARNING").unwrap(), LogLevel::Warning);
+    assert!(LogLevel::try_from("Warning").is_err());
+
+    enhanced_enum!(HttpStatus, rename_all = "kebab-case", case_insensitive {
+        NotFound, InternalServerError,
+    });
+    assert_eq!(HttpStatus::NotFound.to_str(), "not-found");
+    assert_eq!(HttpStatus::try_from("Not-Found").unwrap(), HttpStatus::NotFound);
+    assert_eq!(HttpStatus::try_from("internal-server-error").unwrap(), HttpStatus::InternalServerError);
+
+    enhanced_enum!(FieldName, rename_all = "camelCase" { UserId, FirstName });
+    assert_eq!(FieldName::UserId.to_str(), "userId");
+    assert_eq!(FieldName::FirstName.to_str(), "firstName");
+
+    enhanced_enum!(EventName, rename_all = "PascalCase" { UserCreated });
+    assert_eq!(EventName::UserCreated.to_str(), "UserCreated");
+
+    // `rename_all` combined with sparse explicit discriminants: the renamed
+    // lookup table is indexed by declaration order, not by discriminant.
+    enhanced_enum!(SparseCode, rename_all = "snake_case" { FooBar = 5, BazQux = 10 });
+    assert_eq!(SparseCode::FooBar.to_str(), "foo_bar");
+    assert_eq!(SparseCode::BazQux.to_str(), "baz_qux");
+    assert_eq!(SparseCode::FooBar.to_string(), "foo_bar");
+    assert_eq!(SparseCode::try_from("baz_qux").unwrap(), SparseCode::BazQux);
+
+    // A numeric string that overflows u32 must return Err, not panic.
+    assert!(Suit::try_from("5000000000").is_err());
+    assert!(Suit::from_str("5000000000").is_err());
+}
+
+#[test]
+fn test_array_builder_safety() {
+    enhanced_enum!(Color { Red, Green, Blue });
+
+    struct DropCounter<'a>(&'a std::cell::Cell<usize>);
+    impl<'a> Drop for DropCounter<'a> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    // try_new_with stops and returns Err as soon as one variant fails,
+    // dropping only the elements already constructed before that point.
+    let drops = std::cell::Cell::new(0);
+    let result: Result<ColorArray<DropCounter>, &str> = ColorArray::try_new_with(|c| {
+        if c == Color::Blue { Err("boom") } else { Ok(DropCounter(&drops)) }
+    });
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 2);
+
+    // A panic partway through also only drops the already-initialized prefix.
+    let drops = std::cell::Cell::new(0);
+    let outcome = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        ColorArray::new_with(|c| {
+            if c == Color::Blue { panic!("boom") }
+            DropCounter(&drops)
+        })
+    }));
+    assert!(outcome.is_err());
+    assert_eq!(drops.get(), 2);
+}
+
+#[test]
+fn test_array_ops() {
+    enhanced_enum!(Color { Red, Green, Blue });
+
+    let sizes = ColorArray::from_fn(|c| match c {
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Blue => 3,
+    });
+    assert_eq!(sizes[Color::Blue], 3);
+
+    let ok: Result<ColorArray<i32>, &str> = ColorArray::try_new_with(|_| Ok(1));
+    assert_eq!(ok.unwrap()[Color::Red], 1);
+    let err: Result<ColorArray<i32>, &str> = ColorArray::try_new_with(|c| {
+        if c == Color::Blue { Err("boom") } else { Ok(1) }
+    });
+    assert_eq!(err, Err("boom"));
+
+    let names = ColorArray::new_with(|c| c.to_str());
+    let zipped = sizes.zip(names);
+    assert_eq!(zipped[Color::Green], (2, "Green"));
+
+    let summed = sizes.zip_map(ColorArray::new_with(|_| 10), |a, b| a + b);
+    assert_eq!(summed[Color::Red], 11);
+
+    assert_eq!(sizes.fold(0, |acc, x| acc + x), 6);
+    assert_eq!(sizes.reduce(|a, b| a + b), Some(6));
+
+    let as_slice: &[i32] = sizes.as_ref();
+    assert_eq!(as_slice.len(), 3);
+
+    assert_eq!(sizes.to_string(), "Red: 1, Green: 2, Blue: 3");
+
+    let collected: ColorArray<i32> = vec![
+        (Color::Red, 1), (Color::Green, 2), (Color::Blue, 3),
+    ].into_iter().collect();
+    assert_eq!(collected[Color::Blue], 3);
+}
+
+#[test]
+fn test_properties() {
+    enhanced_enum!(Planet {
+        Earth { mass: "5.97e24", moons: "1" },
+        Mars { mass: "6.4e23", moons: "2", message: "the red planet" },
+    });
+
+    assert_eq!(Planet::Earth.get("mass"), Some("5.97e24"));
+    assert_eq!(Planet::Earth.get("moons"), Some("1"));
+    assert_eq!(Planet::Earth.get("nonexistent"), None);
+    assert_eq!(Planet::Earth.message(), None);
+    assert_eq!(Planet::Earth.detailed_message(), None);
+
+    assert_eq!(Planet::Mars.get("mass"), Some("6.4e23"));
+    assert_eq!(Planet::Mars.message(), Some("the red planet"));
+    assert_eq!(Planet::Mars.detailed_message(), Some("the red planet"));
+
+    enhanced_enum!(Mood { Happy, Sad { message: "feeling blue" } });
+    assert_eq!(Mood::Happy.message(), None);
+    assert_eq!(Mood::Sad.message(), Some("feeling blue"));
+}
+
+#[test]
+fn test_discriminants() {
+    enhanced_enum!(Errno { Perm = 1, NoEnt = 2, Io = 5 });
+
+    assert_eq!(Errno::Perm as u32, 1);
+    assert_eq!(Errno::NoEnt as u32, 2);
+    assert_eq!(Errno::Io as u32, 5);
+
+    assert_eq!(Errno::try_from(5u32).unwrap(), Errno::Io);
+    assert!(Errno::try_from(3u32).is_err());
+    assert_eq!(Errno::try_from("Io").unwrap(), Errno::Io);
+    assert_eq!(Errno::try_from("5").unwrap(), Errno::Io);
+
+    assert_eq!(Errno::count(), 3);
+    assert_eq!(Errno::iter().collect::<Vec<_>>(), vec![Errno::Perm, Errno::NoEnt, Errno::Io]);
+
+    let mut codes = ErrnoArray::new_with(|e| match e {
+        Errno::Perm => "permission denied",
+        Errno::NoEnt => "no such file",
+        Errno::Io => "io error",
+    });
+    assert_eq!(codes[Errno::Io], "io error");
+    codes[Errno::Perm] = "nope";
+    assert_eq!(codes.iter_enumerate().count(), 3);
+
+    let mut set = ErrnoSet::new();
+    set.insert(Errno::Io);
+    assert!(set.contains(Errno::Io));
+    assert!(!set.contains(Errno::Perm));
+    assert_eq!(set.iter().collect::<Vec<_>>(), vec![Errno::Io]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    enhanced_enum!(Direction { North, East, South, West });
+
+    let json = serde_json::to_string(&Direction::East).unwrap();
+    assert_eq!(json, "\"East\"");
+    let back: Direction = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, Direction::East);
+    assert!(serde_json::from_str::<Direction>("\"Nowhere\"").is_err());
+
+    let array = DirectionArray::new_with(|d| match d {
+        Direction::North => 1,
+        Direction::East => 2,
+        Direction::South => 3,
+        Direction::West => 4,
+    });
+    let json = serde_json::to_string(&array).unwrap();
+    let back: DirectionArray<i32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, array);
+
+    assert!(serde_json::from_str::<DirectionArray<i32>>(r#"{"North": 1}"#).is_err());
+}
+
+#[cfg(feature = "pyo3")]
+#[test]
+fn test_pyo3() {
+    use pyo3::prelude::*;
+
+    enhanced_enum!(Shape { Circle, Square, Triangle });
+
+    Python::with_gil(|py| {
+        let obj: Py<Shape> = Py::new(py, Shape::Square).unwrap();
+
+        let as_int: i64 = obj.as_ref(py).call_method0("__int__").unwrap().extract().unwrap();
+        assert_eq!(as_int, 1);
+
+        let as_str: String = obj.as_ref(py).str().unwrap().extract().unwrap();
+        assert_eq!(as_str, "Square");
+
+        let cls = py.get_type::<Shape>();
+        let attr: Shape = cls.getattr("Circle").unwrap().extract().unwrap();
+        assert_eq!(attr, Shape::Circle);
+
+        let into_py: PyObject = Shape::Triangle.into_py(py);
+        let extracted: Shape = into_py.extract(py).unwrap();
+        assert_eq!(extracted, Shape::Triangle);
+
+        assert!(obj.as_ref(py).gt(cls.getattr("Circle").unwrap()).unwrap());
+        assert!(obj.as_ref(py).eq(cls.getattr("Square").unwrap()).unwrap());
+    });
+
+    enhanced_enum!(BigCode { Only = 1000 });
+    Python::with_gil(|py| {
+        let obj: Py<BigCode> = Py::new(py, BigCode::Only).unwrap();
+        let as_int: i64 = obj.as_ref(py).call_method0("__int__").unwrap().extract().unwrap();
+        assert_eq!(as_int, 1000);
+    });
+}